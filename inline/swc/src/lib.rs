@@ -5,7 +5,11 @@
 //! This is the Rust/Wasm counterpart of the JS-based transform in
 //! `../transform.ts`. Both produce identical output.
 
+use std::collections::HashSet;
+
+use serde::Deserialize;
 use swc_core::{
+    atoms::Atom,
     common::{comments::{CommentKind, Comments}, Span, SyntaxContext, DUMMY_SP},
     ecma::{
         ast::*,
@@ -14,6 +18,10 @@ use swc_core::{
     plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
 };
 
+/// A local binding identity: the symbol together with its syntax context,
+/// used to tell apart same-named bindings from different scopes.
+type BindingId = (Atom, SyntaxContext);
+
 /// The private identifier used for the imported `inline` function.
 const INLINE_BINDING: &str = "$$inline";
 
@@ -23,25 +31,98 @@ const INLINE_MODULE: &str = "@effectionx/inline";
 /// The directive string that disables the inline transform.
 const NO_INLINE_DIRECTIVE: &str = "no inline";
 
+/// The call-site pragma that excludes a single `yield*` from the inline
+/// transform, borrowing the leading-comment convention minifiers use for
+/// `/*#__PURE__*/`.
+const NOINLINE_PRAGMA: &str = "#__NOINLINE__";
+
+/// Options controlling the inline transform, deserialized from the plugin
+/// config JSON passed via `TransformPluginProgramMetadata::get_transform_plugin_config()`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct InlineOptions {
+    /// The module specifier to import the inline helper from.
+    #[serde(default = "InlineOptions::default_module")]
+    pub module: String,
+    /// The local binding name for the imported inline helper.
+    #[serde(default = "InlineOptions::default_binding")]
+    pub binding: String,
+    /// The directive string that disables the inline transform for a file.
+    #[serde(default = "InlineOptions::default_directive")]
+    pub directive: String,
+    /// Whether to also wrap plain (non-delegate) `yield` expressions.
+    #[serde(default)]
+    pub wrap_plain_yield: bool,
+    /// When set, only `yield*` delegates whose callee resolves to an import
+    /// from one of these module specifiers are rewritten; delegates to
+    /// generators from other modules are left untouched. `None` inlines
+    /// every delegate, regardless of where it came from.
+    #[serde(default)]
+    pub only_imports_from: Option<Vec<String>>,
+}
+
+impl InlineOptions {
+    fn default_module() -> String {
+        INLINE_MODULE.to_string()
+    }
+
+    fn default_binding() -> String {
+        INLINE_BINDING.to_string()
+    }
+
+    fn default_directive() -> String {
+        NO_INLINE_DIRECTIVE.to_string()
+    }
+}
+
+impl Default for InlineOptions {
+    fn default() -> Self {
+        Self {
+            module: Self::default_module(),
+            binding: Self::default_binding(),
+            directive: Self::default_directive(),
+            wrap_plain_yield: false,
+            only_imports_from: None,
+        }
+    }
+}
+
+/// Walk down the head of a delegate's argument expression to find the
+/// identifier it ultimately resolves to, handling `foo()`, `foo.bar()`, and
+/// a bare `foo`.
+fn root_ident(expr: &Expr) -> Option<BindingId> {
+    match expr {
+        Expr::Ident(ident) => Some((ident.sym.clone(), ident.ctxt)),
+        Expr::Call(call) => match &call.callee {
+            Callee::Expr(callee) => root_ident(callee),
+            _ => None,
+        },
+        Expr::Member(member) => root_ident(&member.obj),
+        _ => None,
+    }
+}
+
 /// Check if a statement is a `"no inline"` directive (a string literal expression statement).
-fn is_no_inline_directive(stmt: &Stmt) -> bool {
+fn is_no_inline_directive(stmt: &Stmt, directive: &str) -> bool {
     if let Stmt::Expr(ExprStmt { expr, .. }) = stmt {
         if let Expr::Lit(Lit::Str(s)) = &**expr {
-            return &*s.value == NO_INLINE_DIRECTIVE;
+            return &*s.value == directive;
         }
     }
     false
 }
 
 /// Check if a module item is a `"no inline"` directive.
-fn is_no_inline_module_directive(item: &ModuleItem) -> bool {
+fn is_no_inline_module_directive(item: &ModuleItem, directive: &str) -> bool {
     if let ModuleItem::Stmt(stmt) = item {
-        return is_no_inline_directive(stmt);
+        return is_no_inline_directive(stmt, directive);
     }
     false
 }
 
 pub struct InlineTransformVisitor {
+    /// The configured options for this transform run.
+    options: InlineOptions,
     /// Whether we are currently inside a generator function.
     generator_depth: u32,
     /// Whether any yield* was transformed (triggers import injection).
@@ -50,15 +131,25 @@ pub struct InlineTransformVisitor {
     skip_file: bool,
     /// Optional comments handle for checking `@noinline` JSDoc annotations.
     comments: Option<Box<dyn Comments>>,
+    /// Local bindings imported from one of `options.only_imports_from`'s
+    /// modules. Populated once per module before visiting its body; empty
+    /// (and unused) when `only_imports_from` is `None`.
+    allowed_imports: HashSet<BindingId>,
 }
 
 impl InlineTransformVisitor {
     pub fn new() -> Self {
+        Self::with_options(InlineOptions::default())
+    }
+
+    pub fn with_options(options: InlineOptions) -> Self {
         Self {
+            options,
             generator_depth: 0,
             transformed: false,
             skip_file: false,
             comments: None,
+            allowed_imports: HashSet::new(),
         }
     }
 
@@ -77,17 +168,33 @@ impl InlineTransformVisitor {
         false
     }
 
-    /// Create the `$$inline` identifier.
-    fn inline_ident(&self) -> Ident {
-        Ident::new_no_ctxt(INLINE_BINDING.into(), DUMMY_SP)
+    /// Check if leading comments on a span contain the `/*#__NOINLINE__*/`
+    /// call-site pragma, used to exclude a single `yield*` from the
+    /// transform while leaving the rest of the generator inlined.
+    fn has_noinline_pragma(&self, span: &Span) -> bool {
+        if let Some(comments) = &self.comments {
+            if let Some(leading) = comments.get_leading(span.lo) {
+                return leading
+                    .iter()
+                    .any(|c| c.kind == CommentKind::Block && c.text.contains(NOINLINE_PRAGMA));
+            }
+        }
+        false
     }
 
-    /// Wrap an expression in `$$inline(expr)`.
-    fn inline_call(&self, arg: Box<Expr>) -> Box<Expr> {
+    /// Create the `$$inline` identifier, positioned at `span`.
+    fn inline_ident(&self, span: Span) -> Ident {
+        Ident::new_no_ctxt(self.options.binding.as_str().into(), span)
+    }
+
+    /// Wrap an expression in `$$inline(expr)`, reusing `span` (the original
+    /// `yield*` expression's span) for the call so source maps resolve back
+    /// to the user's code.
+    fn inline_call(&self, arg: Box<Expr>, span: Span) -> Box<Expr> {
         Box::new(Expr::Call(CallExpr {
-            span: DUMMY_SP,
+            span,
             ctxt: SyntaxContext::empty(),
-            callee: Callee::Expr(Box::new(Expr::Ident(self.inline_ident()))),
+            callee: Callee::Expr(Box::new(Expr::Ident(self.inline_ident(span)))),
             args: vec![ExprOrSpread {
                 spread: None,
                 expr: arg,
@@ -97,18 +204,58 @@ impl InlineTransformVisitor {
     }
 
     /// Build `(yield $$inline(expr))` — a parenthesized non-delegate yield
-    /// wrapping an inline call.
-    fn yield_inline(&self, arg: Box<Expr>) -> Expr {
+    /// wrapping an inline call. `span` is the span of the `yield*`/`yield`
+    /// expression being replaced, and is reused for both the outer `yield`
+    /// and the inner `$$inline(...)` call.
+    fn yield_inline(&self, arg: Box<Expr>, span: Span) -> Expr {
         Expr::Paren(ParenExpr {
-            span: DUMMY_SP,
+            span,
             expr: Box::new(Expr::Yield(YieldExpr {
-                span: DUMMY_SP,
-                arg: Some(self.inline_call(arg)),
+                span,
+                arg: Some(self.inline_call(arg, span)),
                 delegate: false,
             })),
         })
     }
 
+    /// Record every local binding imported from one of
+    /// `options.only_imports_from`'s modules, so delegates calling into
+    /// them can be recognized in `visit_mut_expr`. No-op when
+    /// `only_imports_from` is `None`.
+    fn collect_allowed_imports(&mut self, module: &Module) {
+        let Some(allowed_modules) = &self.options.only_imports_from else {
+            return;
+        };
+
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if !allowed_modules.iter().any(|m| m.as_str() == &*import.src.value) {
+                continue;
+            }
+            for specifier in &import.specifiers {
+                let local = match specifier {
+                    ImportSpecifier::Named(s) => &s.local,
+                    ImportSpecifier::Default(s) => &s.local,
+                    ImportSpecifier::Namespace(s) => &s.local,
+                };
+                self.allowed_imports.insert((local.sym.clone(), local.ctxt));
+            }
+        }
+    }
+
+    /// Whether a delegate's argument expression is allowed to be inlined
+    /// under `options.only_imports_from`: always true when that option is
+    /// unset, otherwise only when the argument's root identifier resolves
+    /// to one of the collected `allowed_imports`.
+    fn is_delegate_allowed(&self, arg: &Expr) -> bool {
+        if self.options.only_imports_from.is_none() {
+            return true;
+        }
+        root_ident(arg).is_some_and(|id| self.allowed_imports.contains(&id))
+    }
+
     /// Build the import declaration:
     /// `import { inline as $$inline } from "@effectionx/inline";`
     fn inline_import(&self) -> ModuleItem {
@@ -116,7 +263,7 @@ impl InlineTransformVisitor {
             span: DUMMY_SP,
             specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
                 span: DUMMY_SP,
-                local: self.inline_ident(),
+                local: self.inline_ident(DUMMY_SP),
                 imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
                     "inline".into(),
                     DUMMY_SP,
@@ -125,7 +272,7 @@ impl InlineTransformVisitor {
             })],
             src: Box::new(Str {
                 span: DUMMY_SP,
-                value: INLINE_MODULE.into(),
+                value: self.options.module.as_str().into(),
                 raw: None,
             }),
             type_only: false,
@@ -138,12 +285,18 @@ impl InlineTransformVisitor {
 impl VisitMut for InlineTransformVisitor {
     fn visit_mut_module(&mut self, module: &mut Module) {
         // Check for file-level "no inline" directive
-        if module.body.first().is_some_and(is_no_inline_module_directive) {
+        if module
+            .body
+            .first()
+            .is_some_and(|item| is_no_inline_module_directive(item, &self.options.directive))
+        {
             self.skip_file = true;
             module.body.remove(0);
             return;
         }
 
+        self.collect_allowed_imports(module);
+
         // Visit all children first to collect transforms
         module.visit_mut_children_with(self);
 
@@ -155,7 +308,11 @@ impl VisitMut for InlineTransformVisitor {
 
     fn visit_mut_script(&mut self, script: &mut Script) {
         // Check for file-level "no inline" directive
-        if script.body.first().is_some_and(is_no_inline_directive) {
+        if script
+            .body
+            .first()
+            .is_some_and(|stmt| is_no_inline_directive(stmt, &self.options.directive))
+        {
             self.skip_file = true;
             script.body.remove(0);
             return;
@@ -189,10 +346,19 @@ impl VisitMut for InlineTransformVisitor {
         }
 
         if let Expr::Yield(yield_expr) = expr {
-            if yield_expr.delegate {
+            let should_transform = if yield_expr.delegate {
+                yield_expr
+                    .arg
+                    .as_deref()
+                    .is_some_and(|arg| self.is_delegate_allowed(arg))
+            } else {
+                self.options.wrap_plain_yield
+            };
+
+            if should_transform && !self.has_noinline_pragma(&yield_expr.span) {
                 if let Some(arg) = yield_expr.arg.take() {
                     self.transformed = true;
-                    *expr = self.yield_inline(arg);
+                    *expr = self.yield_inline(arg, yield_expr.span);
                 }
             }
         }
@@ -204,7 +370,12 @@ pub fn process_transform(
     mut program: Program,
     metadata: TransformPluginProgramMetadata,
 ) -> Program {
-    let mut visitor = InlineTransformVisitor::new();
+    let options = match metadata.get_transform_plugin_config() {
+        Some(config) => serde_json::from_str(&config)
+            .unwrap_or_else(|e| panic!("@effectionx/inline: invalid plugin config: {e}\nconfig was: {config}")),
+        None => InlineOptions::default(),
+    };
+    let mut visitor = InlineTransformVisitor::with_options(options);
     if let Some(comments) = metadata.comments {
         visitor = visitor.with_comments(comments);
     }
@@ -309,10 +480,160 @@ mod tests {
         r#"function* gen() { let x = yield* foo(); }"#
     );
 
+    // Configurable options
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(InlineTransformVisitor::with_options(InlineOptions {
+            binding: "$$customInline".to_string(),
+            module: "my-fork/inline".to_string(),
+            ..Default::default()
+        })),
+        custom_binding_and_module,
+        r#"function* gen() { let x = yield* foo(); }"#,
+        r#"function* gen() { let x = yield $$customInline(foo()); }"#
+    );
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(InlineTransformVisitor::with_options(InlineOptions {
+            wrap_plain_yield: true,
+            ..Default::default()
+        })),
+        wrap_plain_yield_option,
+        r#"function* gen() { let x = yield foo(); }"#,
+        r#"function* gen() { let x = yield $$inline(foo()); }"#
+    );
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(InlineTransformVisitor::with_options(InlineOptions {
+            directive: "disable-inline".to_string(),
+            ..Default::default()
+        })),
+        custom_directive_skips_file,
+        r#""disable-inline";
+        function* gen() { let x = yield* foo(); }"#,
+        r#"function* gen() { let x = yield* foo(); }"#
+    );
+
+    // Import-aware selective inlining
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(InlineTransformVisitor::with_options(InlineOptions {
+            only_imports_from: Some(vec!["effection".to_string()]),
+            ..Default::default()
+        })),
+        selective_inlining_by_import,
+        r#"import { call } from "effection";
+        import { other } from "other-lib";
+        function* gen() {
+            let a = yield* call();
+            let b = yield* other();
+        }"#,
+        r#"import { inline as $$inline } from "@effectionx/inline";
+        import { call } from "effection";
+        import { other } from "other-lib";
+        function* gen() {
+            let a = yield $$inline(call());
+            let b = yield* other();
+        }"#
+    );
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(InlineTransformVisitor::with_options(InlineOptions {
+            only_imports_from: Some(vec!["effection".to_string()]),
+            ..Default::default()
+        })),
+        selective_inlining_through_member_access,
+        r#"import * as effection from "effection";
+        function* gen() {
+            let a = yield* effection.call();
+            let b = yield* other.call();
+        }"#,
+        r#"import { inline as $$inline } from "@effectionx/inline";
+        import * as effection from "effection";
+        function* gen() {
+            let a = yield $$inline(effection.call());
+            let b = yield* other.call();
+        }"#
+    );
+
+    // Span preservation
+
+    #[test]
+    fn inline_call_reuses_yield_star_span() {
+        use swc_core::common::BytePos;
+
+        let original_span = Span::new(BytePos(42), BytePos(55));
+        let visitor = InlineTransformVisitor::new();
+
+        let mut expr = Expr::Yield(YieldExpr {
+            span: original_span,
+            arg: Some(Box::new(Expr::Ident(Ident::new_no_ctxt(
+                "foo".into(),
+                DUMMY_SP,
+            )))),
+            delegate: true,
+        });
+
+        let Expr::Yield(yield_expr) = &mut expr else {
+            unreachable!()
+        };
+        let arg = yield_expr.arg.take().unwrap();
+        let wrapped = visitor.yield_inline(arg, yield_expr.span);
+
+        let Expr::Paren(paren) = &wrapped else {
+            panic!("expected parenthesized yield");
+        };
+        let Expr::Yield(inner_yield) = &*paren.expr else {
+            panic!("expected yield expression");
+        };
+        let Expr::Call(call) = &**inner_yield.arg.as_ref().unwrap() else {
+            panic!("expected $$inline call");
+        };
+
+        assert_eq!(paren.span, original_span);
+        assert_eq!(inner_yield.span, original_span);
+        assert_eq!(call.span, original_span);
+        assert_ne!(call.span, DUMMY_SP);
+    }
+
+    // Call-site `/*#__NOINLINE__*/` pragma
+
+    #[test]
+    fn noinline_pragma_suppresses_single_call_site() {
+        use swc_core::common::comments::{Comment, CommentKind, SingleThreadedComments};
+        use swc_core::common::BytePos;
+
+        let span = Span::new(BytePos(10), BytePos(20));
+        let comments = SingleThreadedComments::default();
+        comments.add_leading(
+            span.lo,
+            Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: "#__NOINLINE__".into(),
+            },
+        );
+
+        let visitor = InlineTransformVisitor::new().with_comments(comments);
+        assert!(visitor.has_noinline_pragma(&span));
+
+        let other_span = Span::new(BytePos(30), BytePos(40));
+        assert!(!visitor.has_noinline_pragma(&other_span));
+    }
+
     // NOTE: @noinline JSDoc annotation tests cannot be written with test_inline!
-    // because the macro's run_captured() does not set COMMENTS. The JSDoc
-    // annotation is tested in the JS transform tests. In production (wasm),
-    // comments are provided via PluginCommentsProxy.
+    // because the macro's run_captured() does not set COMMENTS. In production
+    // (wasm), comments are provided via PluginCommentsProxy.
+    //
+    // The same applies to using `test_inline!` for the `/*#__NOINLINE__*/`
+    // call-site pragma: `has_noinline_pragma` is unit-tested above, and
+    // `exec_equivalence::noinline_pragma_suppresses_rewrite_end_to_end`
+    // covers the full parse → transform path with real comments attached.
 
     // NOTE: The fixer() pass strips redundant parens from the IIFE, so
     // `(function*() {...})()` becomes `function*() {...}()` in expected output.
@@ -333,3 +654,203 @@ mod tests {
         }"#
     );
 }
+
+/// Execution-based equivalence harness for the inline transform.
+///
+/// Modeled on swc_ecma_minifier's fixture-driven `compress.rs` tests: each
+/// fixture under `tests/fixtures/exec` is run through Node.js twice — once
+/// as written (exercising the real `yield*` delegation) and once after
+/// passing it through [`InlineTransformVisitor`] (exercising `yield
+/// $$inline(...)`) — against a stub `$$inline` that just drains the
+/// generator it's given. Both runs record an explicit `trace` of side
+/// effects plus the generator's final return value, and the two traces
+/// must match exactly.
+///
+/// A plain per-yield replay wouldn't work here: the transform collapses
+/// however many times a delegate generator yields into a single pause at
+/// the call site, so the two variants legitimately suspend a different
+/// number of times. Side effects recorded via `trace.push(...)` happen at
+/// the same point in program order regardless of where the suspension
+/// boundaries are, which is what this harness compares instead.
+#[cfg(test)]
+mod exec_equivalence {
+    use std::fs;
+    use std::process::Command;
+
+    use super::InlineTransformVisitor;
+    use swc_core::ecma::codegen::{text_writer::JsWriter, Config as CodegenConfig, Emitter};
+    use swc_core::ecma::parser::{lexer::Lexer, Parser, StringInput, Syntax};
+    use swc_core::ecma::visit::VisitMutWith;
+
+    /// Parse `src` as a script, run it through the inline transform, and
+    /// re-emit it as JS source.
+    fn transform_source(src: &str) -> String {
+        let cm: swc_core::common::sync::Lrc<swc_core::common::SourceMap> = Default::default();
+        let fm = cm.new_source_file(swc_core::common::FileName::Anon.into(), src.to_string());
+        let lexer = Lexer::new(
+            Syntax::Es(Default::default()),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        let mut script = parser.parse_script().expect("fixture parses as a script");
+
+        let mut visitor = InlineTransformVisitor::new();
+        script.visit_mut_with(&mut visitor);
+
+        let mut buf = Vec::new();
+        {
+            let writer = JsWriter::new(cm.clone(), "\n", &mut buf, None);
+            let mut emitter = Emitter {
+                cfg: CodegenConfig::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: writer,
+            };
+            emitter.emit_script(&script).expect("transformed fixture re-emits");
+        }
+        String::from_utf8(buf).expect("emitted source is valid utf8")
+    }
+
+    /// Run `script` with `node -e`, returning its trimmed stdout, or `None`
+    /// if `node` isn't available in this environment.
+    fn run_node(script: &str) -> Option<String> {
+        if Command::new("node").arg("--version").output().is_err() {
+            return None;
+        }
+        let output = Command::new("node")
+            .arg("-e")
+            .arg(script)
+            .output()
+            .expect("failed to spawn node");
+        assert!(
+            output.status.success(),
+            "node script failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Some(String::from_utf8(output.stdout).unwrap().trim().to_string())
+    }
+
+    /// Wrap a fixture body with a stub `inline`/`$$inline`/`drive` runtime
+    /// and a final `console.log` of the recorded trace and return value.
+    fn harness(body: &str) -> String {
+        format!(
+            r#"
+const trace = [];
+function inline(g) {{
+  let r = g.next();
+  while (!r.done) {{
+    r = g.next(r.value);
+  }}
+  return r.value;
+}}
+const $$inline = inline;
+function drive(g) {{
+  let r = g.next();
+  while (!r.done) {{
+    r = g.next(r.value);
+  }}
+  return r.value;
+}}
+
+{body}
+
+console.log(JSON.stringify({{ trace, result: drive(gen()) }}));
+"#
+        )
+    }
+
+    /// Run the named fixture both untransformed and transformed, and assert
+    /// their recorded traces and return values match.
+    fn assert_fixture_equivalent(name: &str) {
+        let path = format!(
+            "{}/tests/fixtures/exec/{name}.js",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let source =
+            fs::read_to_string(&path).unwrap_or_else(|e| panic!("failed to read fixture {path}: {e}"));
+
+        let Some(original) = run_node(&harness(&source)) else {
+            eprintln!("skipping exec-equivalence fixture `{name}`: node is not available");
+            return;
+        };
+
+        let transformed_source = transform_source(&source);
+        let transformed =
+            run_node(&harness(&transformed_source)).expect("node was available for the original run");
+
+        assert_eq!(
+            original, transformed,
+            "transformed fixture `{name}` behaves differently than the original"
+        );
+    }
+
+    #[test]
+    fn leaf_operation() {
+        assert_fixture_equivalent("leaf_operation");
+    }
+
+    #[test]
+    fn nested_generators() {
+        assert_fixture_equivalent("nested_generators");
+    }
+
+    #[test]
+    fn for_of() {
+        assert_fixture_equivalent("for_of");
+    }
+
+    /// End-to-end check that `/*#__NOINLINE__*/` suppresses a rewrite
+    /// through a real parse → transform → codegen pass, with comments
+    /// threaded from the lexer into the visitor exactly as `process_transform`
+    /// does via `TransformPluginProgramMetadata::comments`.
+    #[test]
+    fn noinline_pragma_suppresses_rewrite_end_to_end() {
+        use swc_core::common::comments::SingleThreadedComments;
+
+        let src = r#"
+function* gen() {
+  let a = /*#__NOINLINE__*/ yield* foo();
+  let b = yield* bar();
+}
+"#;
+
+        let cm: swc_core::common::sync::Lrc<swc_core::common::SourceMap> = Default::default();
+        let fm = cm.new_source_file(swc_core::common::FileName::Anon.into(), src.to_string());
+        let comments = SingleThreadedComments::default();
+        let lexer = Lexer::new(
+            Syntax::Es(Default::default()),
+            Default::default(),
+            StringInput::from(&*fm),
+            Some(&comments),
+        );
+        let mut parser = Parser::new_from(lexer);
+        let mut script = parser.parse_script().expect("fixture parses as a script");
+
+        let mut visitor = InlineTransformVisitor::new().with_comments(comments);
+        script.visit_mut_with(&mut visitor);
+
+        let mut buf = Vec::new();
+        {
+            let writer = JsWriter::new(cm.clone(), "\n", &mut buf, None);
+            let mut emitter = Emitter {
+                cfg: CodegenConfig::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: writer,
+            };
+            emitter.emit_script(&script).expect("fixture re-emits");
+        }
+        let output = String::from_utf8(buf).expect("emitted source is valid utf8");
+
+        assert!(
+            output.contains("yield* foo()"),
+            "the pragma'd delegate should stay untouched, got: {output}"
+        );
+        assert!(
+            output.contains("$$inline(bar())"),
+            "the non-pragma'd delegate should still be inlined, got: {output}"
+        );
+    }
+}